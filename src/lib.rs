@@ -1,13 +1,14 @@
+use pyo3::exceptions::PyBufferError;
+use pyo3::ffi;
 use pyo3::prelude::*;
 use pyo3::types::PyBytes;
+use pyo3::AsPyPointer;
 use resvg::Tree as RenderTree;
 use resvg::tiny_skia::{Pixmap, Transform};
-use resvg::usvg::{Options, TreeTextToPath, Size, Tree, TreeParsing, fontdb};
+use resvg::usvg::{Options, TreeTextToPath, Size, Tree, TreeParsing, fontdb, Node, NodeExt};
 
 
 /// SVG parsing and rendering options.
-///
-/// TODO(edgarmondragon): Add more options.
 #[derive(Clone)]
 #[pyclass]
 pub struct SVGOptions {
@@ -37,23 +38,105 @@ pub struct SVGOptions {
     ///
     /// Default: 100.0
     default_height: f32,
+
+    /// Directories to scan for `.ttf`/`.otf`/`.ttc` font files, in addition
+    /// to (or instead of) the system fonts.
+    ///
+    /// Default: `[]`
+    font_dirs: Vec<std::path::PathBuf>,
+
+    /// Individual font files to load.
+    ///
+    /// Default: `[]`
+    font_files: Vec<std::path::PathBuf>,
+
+    /// Whether to load the system's installed fonts.
+    ///
+    /// Disable this for server deployments that only ship their own fonts,
+    /// to avoid the cost of scanning the system font directories.
+    ///
+    /// Default: `true`
+    load_system_fonts: bool,
+
+    /// Font family to substitute for the generic CSS `serif` family.
+    ///
+    /// Default: `"Times New Roman"`
+    serif_family: String,
+
+    /// Font family to substitute for the generic CSS `sans-serif` family.
+    ///
+    /// Default: `"Arial"`
+    sans_serif_family: String,
+
+    /// Font family to substitute for the generic CSS `monospace` family.
+    ///
+    /// Default: `"Courier New"`
+    monospace_family: String,
+
+    /// Font family to substitute for the generic CSS `cursive` family.
+    ///
+    /// Default: `"Comic Sans MS"`
+    cursive_family: String,
+
+    /// Font family to substitute for the generic CSS `fantasy` family.
+    ///
+    /// Default: `"Impact"`
+    fantasy_family: String,
+
+    /// Font family to use for text that has no `font-family` set.
+    ///
+    /// Default: `"Times New Roman"`
+    default_font_family: String,
 }
 
 #[pymethods]
 impl SVGOptions {
     #[new]
-    #[pyo3(signature = (*, dpi = 96.0, default_width = 100.0, default_height = 100.0, resources_dir = None))]
+    #[pyo3(signature = (
+        *,
+        dpi = 96.0,
+        default_width = 100.0,
+        default_height = 100.0,
+        resources_dir = None,
+        font_dirs = Vec::new(),
+        font_files = Vec::new(),
+        load_system_fonts = true,
+        serif_family = "Times New Roman".to_string(),
+        sans_serif_family = "Arial".to_string(),
+        monospace_family = "Courier New".to_string(),
+        cursive_family = "Comic Sans MS".to_string(),
+        fantasy_family = "Impact".to_string(),
+        default_font_family = "Times New Roman".to_string()
+    ))]
     fn new(
         dpi: f32,
         default_width: f32,
         default_height: f32,
         resources_dir: Option<std::path::PathBuf>,
+        font_dirs: Vec<std::path::PathBuf>,
+        font_files: Vec<std::path::PathBuf>,
+        load_system_fonts: bool,
+        serif_family: String,
+        sans_serif_family: String,
+        monospace_family: String,
+        cursive_family: String,
+        fantasy_family: String,
+        default_font_family: String,
     ) -> Self {
         Self {
             dpi,
             default_width,
             default_height,
             resources_dir,
+            font_dirs,
+            font_files,
+            load_system_fonts,
+            serif_family,
+            sans_serif_family,
+            monospace_family,
+            cursive_family,
+            fantasy_family,
+            default_font_family,
         }
     }
 }
@@ -62,13 +145,15 @@ impl SVGOptions {
 #[pyclass]
 pub struct Resvg {
     options: Option<SVGOptions>,
+    fontdb: fontdb::Database,
 }
 
 #[pymethods]
 impl Resvg {
     #[new]
     fn new(options: Option<SVGOptions>) -> Self {
-        Self { options }
+        let fontdb = build_fontdb(&options);
+        Self { options, fontdb }
     }
 
     /// Renders SVG to PNG.
@@ -78,47 +163,409 @@ impl Resvg {
     /// * `svg` - String containing SVG data.
     /// * `width` - Width of the output image.
     /// * `height` - Height of the output image.
+    /// * `fit` - How to fit the SVG's intrinsic size into `width`/`height`:
+    ///   `"stretch"` (default) scales each axis independently, `"contain"`
+    ///   preserves aspect ratio and centers the result inside the output,
+    ///   `"cover"` preserves aspect ratio and fills the output, `"none"`
+    ///   renders at 1:1 scale.
+    /// * `export_id` - If set, render only the subtree rooted at the element
+    ///   with this `id`, tightly cropped to its bounding box. `width`/`height`
+    ///   and `fit` are ignored in this case.
+    /// * `trim` - If `true`, crop the result to the tightest rectangle
+    ///   containing any non-transparent pixel. See `RenderedImage.trim`.
+    /// * `background` - Solid color to composite the rendered image onto
+    ///   (after `trim`, if set), as `None` (transparent, the default), a CSS-style color
+    ///   string (e.g. `"#fff"`, `"#ffffffff"`), or an `(r, g, b)` /
+    ///   `(r, g, b, a)` tuple of `0..=255` ints.
     ///
     /// # Returns
     ///
-    /// A numpy array of shape (height, width, 4) containing RGBA data.
-    fn render(&self, svg: &str, width: u32, height: u32) -> RenderedImage {
-        let mut pixmap = Pixmap::new(width, height).unwrap();
+    /// A `RenderedImage` wrapping the rendered pixmap. Use `.to_numpy()` for
+    /// a `(height, width, 4)` `uint8` array, `.as_rgba()` for raw RGBA
+    /// bytes, or `.as_png()` to encode to PNG.
+    #[pyo3(signature = (svg, width, height, fit = "stretch", export_id = None, trim = false, background = None))]
+    #[allow(clippy::too_many_arguments)]
+    fn render(
+        &self,
+        svg: &str,
+        width: u32,
+        height: u32,
+        fit: &str,
+        export_id: Option<&str>,
+        trim: bool,
+        background: Option<&PyAny>,
+    ) -> PyResult<RenderedImage> {
+        self.render_impl(svg, width, height, fit, export_id, trim, background, None)
+    }
+
+    /// Reads an SVG file from disk and renders it, like `render`, setting
+    /// `resources_dir` to the file's parent directory so relative `<image>`
+    /// references and the like resolve correctly.
+    #[pyo3(signature = (path, width, height, fit = "stretch", export_id = None, trim = false, background = None))]
+    #[allow(clippy::too_many_arguments)]
+    fn render_file(
+        &self,
+        path: std::path::PathBuf,
+        width: u32,
+        height: u32,
+        fit: &str,
+        export_id: Option<&str>,
+        trim: bool,
+        background: Option<&PyAny>,
+    ) -> PyResult<RenderedImage> {
+        let svg = std::fs::read_to_string(&path)
+            .map_err(|e| pyo3::exceptions::PyIOError::new_err(format!("Failed to read '{}': {}", path.display(), e)))?;
+        let resources_dir = path.parent().map(|p| p.to_path_buf());
+        self.render_impl(&svg, width, height, fit, export_id, trim, background, resources_dir)
+    }
+
+    /// Renders an in-memory SVG document, like `render`, accepting UTF-8
+    /// bytes instead of a `str`.
+    #[pyo3(signature = (data, width, height, fit = "stretch", export_id = None, trim = false, background = None))]
+    #[allow(clippy::too_many_arguments)]
+    fn render_bytes(
+        &self,
+        data: &[u8],
+        width: u32,
+        height: u32,
+        fit: &str,
+        export_id: Option<&str>,
+        trim: bool,
+        background: Option<&PyAny>,
+    ) -> PyResult<RenderedImage> {
+        let svg = std::str::from_utf8(data)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid UTF-8 SVG data: {}", e)))?;
+        self.render_impl(svg, width, height, fit, export_id, trim, background, None)
+    }
+
+    /// Lists the `id` of every element in the SVG that has one.
+    fn node_ids(&self, svg: &str) -> PyResult<Vec<String>> {
+        let mut tree = self.parse_tree(svg)?;
+        tree.convert_text(&self.fontdb);
+        Ok(tree
+            .root
+            .descendants()
+            .map(|node| node.id().to_string())
+            .filter(|id| !id.is_empty())
+            .collect())
+    }
+
+    /// Returns the bounding box of the element with the given `id`, as
+    /// `(x, y, width, height)` in the SVG's coordinate system.
+    fn node_bbox(&self, svg: &str, id: &str) -> PyResult<(f32, f32, f32, f32)> {
+        let mut tree = self.parse_tree(svg)?;
+        tree.convert_text(&self.fontdb);
+        let node = find_node_by_id(&tree, id)?;
+        let bbox = node
+            .calculate_bbox()
+            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(format!("Element '{}' has no bounding box", id)))?;
+        Ok((bbox.x(), bbox.y(), bbox.width(), bbox.height()))
+    }
+
+    /// Returns the resolved pixel width/height of the `<svg>` element
+    /// without rasterizing it.
+    ///
+    /// Falls back to `SVGOptions.default_width`/`default_height` (combined
+    /// with the `viewBox`, if any) when the SVG's `width`/`height` are
+    /// relative or absent, mirroring `usvg`'s own size resolution.
+    fn intrinsic_size(&self, svg: &str) -> PyResult<(f32, f32)> {
+        let tree = self.parse_tree(svg)?;
+        Ok((tree.size.width(), tree.size.height()))
+    }
+
+    /// Returns the `viewBox` as `(x, y, width, height)` in the SVG's
+    /// coordinate system.
+    ///
+    /// `usvg` always resolves a `viewBox` for the tree, defaulting to the
+    /// computed size's rect when the `<svg>` element doesn't declare one,
+    /// so this is never absent.
+    fn viewbox(&self, svg: &str) -> PyResult<(f32, f32, f32, f32)> {
+        let tree = self.parse_tree(svg)?;
+        let rect = tree.view_box.rect;
+        Ok((rect.x(), rect.y(), rect.width(), rect.height()))
+    }
+}
+
+impl Resvg {
+    /// Builds the `usvg::Options` for this instance's `SVGOptions`.
+    fn usvg_options(&self) -> Options {
+        self.usvg_options_with(None)
+    }
+
+    /// Builds the `usvg::Options` for this instance's `SVGOptions`, using
+    /// `resources_dir_override` in place of `SVGOptions.resources_dir` when
+    /// given (e.g. a file's parent directory in `render_file`).
+    fn usvg_options_with(&self, resources_dir_override: Option<std::path::PathBuf>) -> Options {
+        let resources_dir = resources_dir_override
+            .or_else(|| self.options.as_ref().and_then(|o| o.resources_dir.clone()));
 
-        let options = if let Some(options) = &self.options {
+        if let Some(options) = &self.options {
             Options {
                 dpi: options.dpi,
                 default_size: Size::from_wh(options.default_width, options.default_height).unwrap(),
-                resources_dir: options.resources_dir.clone(),
+                font_family: options.default_font_family.clone(),
+                resources_dir,
+                ..Options::default()
+            }
+        } else {
+            Options {
+                resources_dir,
                 ..Options::default()
             }
+        }
+    }
+
+    /// Parses `svg` with this instance's options, without converting text
+    /// to paths or rasterizing.
+    fn parse_tree(&self, svg: &str) -> PyResult<Tree> {
+        self.parse_tree_with(svg, None)
+    }
+
+    /// Parses `svg` with this instance's options, optionally overriding
+    /// `resources_dir` (see `usvg_options_with`), without converting text to
+    /// paths or rasterizing.
+    fn parse_tree_with(&self, svg: &str, resources_dir_override: Option<std::path::PathBuf>) -> PyResult<Tree> {
+        Tree::from_str(svg, &self.usvg_options_with(resources_dir_override))
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Failed to parse SVG: {}", e)))
+    }
+
+    /// Shared implementation backing `render`, `render_file`, and
+    /// `render_bytes`.
+    #[allow(clippy::too_many_arguments)]
+    fn render_impl(
+        &self,
+        svg: &str,
+        width: u32,
+        height: u32,
+        fit: &str,
+        export_id: Option<&str>,
+        trim: bool,
+        background: Option<&PyAny>,
+        resources_dir_override: Option<std::path::PathBuf>,
+    ) -> PyResult<RenderedImage> {
+        let background = background.map(parse_background).transpose()?;
+
+        let mut tree = self.parse_tree_with(svg, resources_dir_override)?;
+
+        tree.convert_text(&self.fontdb);
+
+        // Rendered onto a transparent pixmap first, regardless of
+        // `background`: `trim` needs the content's own alpha, not the
+        // background's, and compositing the background in afterwards gives
+        // the same final pixels for the non-trimmed case.
+        let mut image = if let Some(id) = export_id {
+            let node = find_node_by_id(&tree, id)?;
+            let bbox = node
+                .calculate_bbox()
+                .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(format!("Element '{}' has no bounding box", id)))?;
+
+            let (bbox_w, bbox_h) = (bbox.width().ceil() as u32, bbox.height().ceil() as u32);
+            if bbox_w == 0 || bbox_h == 0 {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "Element '{}' has a degenerate bounding box ({}x{})",
+                    id, bbox_w, bbox_h
+                )));
+            }
+            let mut pixmap = Pixmap::new(bbox_w, bbox_h).unwrap();
+            let transform = Transform::from_translate(-bbox.x(), -bbox.y());
+            resvg::render_node(&tree, &node, transform, &mut pixmap.as_mut())
+                .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(format!("Failed to render element '{}'", id)))?;
+
+            RenderedImage::new(pixmap, (0, 0))
         } else {
-            Options::default()
+            if width == 0 || height == 0 {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "width and height must be non-zero, got {}x{}",
+                    width, height
+                )));
+            }
+            let mut pixmap = Pixmap::new(width, height).unwrap();
+
+            let render_tree = RenderTree::from_usvg(&tree);
+
+            let transform = fit_transform(tree.size.width(), tree.size.height(), width as f32, height as f32, fit)?;
+
+            render_tree.render(transform, &mut pixmap.as_mut());
+
+            RenderedImage::new(pixmap, (0, 0))
         };
 
-        let mut tree = Tree::from_str(svg, &options).unwrap();
+        if trim {
+            image = image.trim();
+        }
 
-        let mut fontdb = fontdb::Database::new();
+        if let Some(color) = background {
+            image = image.composite_onto(color);
+        }
 
-        fontdb.load_system_fonts();
+        Ok(image)
+    }
+}
 
-        tree.convert_text(&fontdb);
-    
-        let render_tree = RenderTree::from_usvg(&tree);
+/// Parses a `background` argument to `render` into a `tiny_skia::Color`:
+/// either a CSS-style hex color string (`#rgb`, `#rrggbb`, `#rrggbbaa`) or
+/// an `(r, g, b)` / `(r, g, b, a)` tuple of `0..=255` ints.
+fn parse_background(obj: &PyAny) -> PyResult<resvg::tiny_skia::Color> {
+    if let Ok(s) = obj.extract::<&str>() {
+        return parse_hex_color(s);
+    }
+    if let Ok((r, g, b, a)) = obj.extract::<(u8, u8, u8, u8)>() {
+        return Ok(resvg::tiny_skia::Color::from_rgba8(r, g, b, a));
+    }
+    if let Ok((r, g, b)) = obj.extract::<(u8, u8, u8)>() {
+        return Ok(resvg::tiny_skia::Color::from_rgba8(r, g, b, 255));
+    }
+    Err(pyo3::exceptions::PyTypeError::new_err(
+        "background must be a color string or an (r, g, b[, a]) tuple of ints",
+    ))
+}
 
-        render_tree.render(
-            Transform::default(),
-            &mut pixmap.as_mut(),
-        );
+/// Parses a `#rgb`, `#rrggbb`, or `#rrggbbaa` color string.
+fn parse_hex_color(s: &str) -> PyResult<resvg::tiny_skia::Color> {
+    let invalid = || pyo3::exceptions::PyValueError::new_err(format!("Invalid color string '{}'", s));
+
+    let hex = s.strip_prefix('#').unwrap_or(s);
+    if !hex.is_ascii() {
+        return Err(invalid());
+    }
+    let expand = |c: char| u8::from_str_radix(&c.to_string().repeat(2), 16);
+
+    let channels: Result<Vec<u8>, _> = match hex.len() {
+        3 | 4 => hex.chars().map(expand).collect(),
+        6 | 8 => (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+            .collect(),
+        _ => return Err(invalid()),
+    };
+
+    let channels = channels.map_err(|_| invalid())?;
+    let a = channels.get(3).copied().unwrap_or(255);
+    Ok(resvg::tiny_skia::Color::from_rgba8(channels[0], channels[1], channels[2], a))
+}
 
-        RenderedImage { pixmap }
+/// Computes the `Transform` that maps an SVG's intrinsic size onto the
+/// requested output dimensions according to `fit`.
+///
+/// Mirrors `object-fit`-style CSS semantics: `"contain"` uses
+/// `zoom = min(sx, sy)` and centers the result, `"cover"` uses
+/// `zoom = max(sx, sy)`, `"stretch"` scales each axis independently, and
+/// `"none"` renders at 1:1 scale.
+fn fit_transform(src_w: f32, src_h: f32, dst_w: f32, dst_h: f32, fit: &str) -> PyResult<Transform> {
+    if src_w <= 0.0 || src_h <= 0.0 {
+        return Ok(Transform::default());
+    }
+
+    let sx = dst_w / src_w;
+    let sy = dst_h / src_h;
+
+    match fit {
+        "none" => Ok(Transform::default()),
+        "stretch" => Ok(Transform::from_scale(sx, sy)),
+        "contain" => {
+            let zoom = sx.min(sy).max(f32::EPSILON);
+            let tx = (dst_w - src_w * zoom) / 2.0;
+            let ty = (dst_h - src_h * zoom) / 2.0;
+            Ok(Transform::from_scale(zoom, zoom).post_translate(tx, ty))
+        }
+        "cover" => {
+            let zoom = sx.max(sy).max(f32::EPSILON);
+            let tx = (dst_w - src_w * zoom) / 2.0;
+            let ty = (dst_h - src_h * zoom) / 2.0;
+            Ok(Transform::from_scale(zoom, zoom).post_translate(tx, ty))
+        }
+        other => Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Unknown fit mode '{}', expected one of: stretch, contain, cover, none",
+            other
+        ))),
     }
 }
 
+/// Builds the `fontdb::Database` for the given `SVGOptions`, loading
+/// bundled fonts and, unless disabled, the system fonts.
+///
+/// Built once per `Resvg` instance and reused across `render` calls,
+/// since scanning the system font directories is expensive.
+fn build_fontdb(options: &Option<SVGOptions>) -> fontdb::Database {
+    let mut db = fontdb::Database::new();
+
+    let Some(options) = options else {
+        db.load_system_fonts();
+        return db;
+    };
+
+    for dir in &options.font_dirs {
+        db.load_fonts_dir(dir);
+    }
+
+    for file in &options.font_files {
+        let _ = db.load_font_file(file);
+    }
+
+    if options.load_system_fonts {
+        db.load_system_fonts();
+    }
+
+    db.set_serif_family(&options.serif_family);
+    db.set_sans_serif_family(&options.sans_serif_family);
+    db.set_monospace_family(&options.monospace_family);
+    db.set_cursive_family(&options.cursive_family);
+    db.set_fantasy_family(&options.fantasy_family);
+
+    db
+}
+
+/// Finds the element with the given `id` in `tree`, or returns a
+/// `PyValueError` if no such element exists.
+fn find_node_by_id(tree: &Tree, id: &str) -> PyResult<Node> {
+    tree.root
+        .descendants()
+        .find(|node| node.id() == id)
+        .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(format!("No element with id '{}'", id)))
+}
+
 /// Rendered image
 #[pyclass]
 pub struct RenderedImage {
     pixmap: Pixmap,
+    /// Offset of `pixmap`'s origin from the original, untrimmed render, in
+    /// pixels. Non-zero only after `trim()`.
+    offset: (u32, u32),
+    /// `(height, width, 4)`, cached alongside `pixmap` so `__getbuffer__`
+    /// can hand out a stable pointer to it.
+    shape: [isize; 3],
+    /// Byte strides matching `shape`, for a contiguous row-major buffer.
+    strides: [isize; 3],
+}
+
+impl RenderedImage {
+    fn new(pixmap: Pixmap, offset: (u32, u32)) -> Self {
+        let (width, height) = (pixmap.width() as isize, pixmap.height() as isize);
+        Self {
+            pixmap,
+            offset,
+            shape: [height, width, 4],
+            strides: [width * 4, 4, 1],
+        }
+    }
+
+    /// Composites this image over a solid `color`, returning a new
+    /// `RenderedImage` of the same size and offset. Used to apply
+    /// `background` after rendering/trimming, so trimming always measures
+    /// the content's own alpha rather than an opaque background's.
+    fn composite_onto(&self, color: resvg::tiny_skia::Color) -> RenderedImage {
+        let mut pixmap = Pixmap::new(self.pixmap.width(), self.pixmap.height()).unwrap();
+        pixmap.fill(color);
+        pixmap.draw_pixmap(
+            0,
+            0,
+            self.pixmap.as_ref(),
+            &resvg::tiny_skia::PixmapPaint::default(),
+            Transform::identity(),
+            None,
+        );
+        RenderedImage::new(pixmap, self.offset)
+    }
 }
 
 #[pymethods]
@@ -133,6 +580,43 @@ impl RenderedImage {
         self.pixmap.height()
     }
 
+    /// Returns the `(x, y)` offset of this image's origin from the
+    /// original, untrimmed render. Non-zero only after `trim()`.
+    pub fn offset(&self) -> (u32, u32) {
+        self.offset
+    }
+
+    /// Whether the pixel data is stored with premultiplied alpha.
+    ///
+    /// `tiny_skia::Pixmap`, which backs this image, always stores
+    /// premultiplied alpha, so this is always `true`. Exposed so downstream
+    /// pipelines can branch on it instead of assuming a convention.
+    pub fn premultiplied(&self) -> bool {
+        true
+    }
+
+    /// Returns the raw RGBA pixel buffer, row-major, 4 bytes per pixel.
+    ///
+    /// By default the bytes are premultiplied alpha, matching the pixmap's
+    /// internal storage. Pass `unpremultiply=True` to convert to straight
+    /// alpha RGBA instead, as `resvg`'s public `Image` type stores it.
+    #[pyo3(signature = (unpremultiply = false))]
+    fn as_rgba(&self, py: Python, unpremultiply: bool) -> PyResult<PyObject> {
+        let mut data = self.pixmap.data().to_vec();
+        if unpremultiply {
+            unpremultiply_rgba(&mut data);
+        }
+        Ok(PyBytes::new(py, &data).into())
+    }
+
+    /// Returns the image as a `(height, width, 4)` `uint8` numpy array,
+    /// sharing memory with this object via the buffer protocol rather than
+    /// copying. The data is premultiplied alpha; unpremultiply it on the
+    /// numpy side if you need straight alpha.
+    fn to_numpy<'py>(slf: PyRef<'py, Self>, py: Python<'py>) -> PyResult<&'py PyAny> {
+        py.import("numpy")?.call_method1("asarray", (slf,))
+    }
+
     /// Returns the rendered image as bytes in PNG format.
     fn as_png(&self, py: Python) -> PyResult<PyObject> {
         self.pixmap
@@ -142,6 +626,120 @@ impl RenderedImage {
                 pyo3::exceptions::PyException::new_err(format!("Failed to encode PNG: {}", e))
             })
     }
+
+    /// Crops the image to the tightest rectangle containing any
+    /// non-transparent pixel, returning a new `RenderedImage`.
+    ///
+    /// The returned image's `offset()` reports where its origin landed in
+    /// the untrimmed image, so callers can reposition it. If every pixel is
+    /// fully transparent, the image is returned unchanged.
+    fn trim(&self) -> RenderedImage {
+        let Some(bounds) = alpha_bounds(&self.pixmap) else {
+            return RenderedImage::new(self.pixmap.clone(), self.offset);
+        };
+        let (x, y, w, h) = bounds;
+
+        let mut cropped = Pixmap::new(w, h).unwrap();
+        cropped.draw_pixmap(
+            -(x as i32),
+            -(y as i32),
+            self.pixmap.as_ref(),
+            &resvg::tiny_skia::PixmapPaint::default(),
+            Transform::identity(),
+            None,
+        );
+
+        RenderedImage::new(cropped, (self.offset.0 + x, self.offset.1 + y))
+    }
+
+    /// Exposes this image's raw pixel buffer via Python's buffer protocol,
+    /// as a read-only `(height, width, 4)` `uint8` array, without copying.
+    unsafe fn __getbuffer__(
+        slf: PyRefMut<'_, Self>,
+        view: *mut ffi::Py_buffer,
+        flags: std::os::raw::c_int,
+    ) -> PyResult<()> {
+        if view.is_null() {
+            return Err(PyBufferError::new_err("View is null"));
+        }
+        if (flags & ffi::PyBUF_WRITABLE) == ffi::PyBUF_WRITABLE {
+            return Err(PyBufferError::new_err("RenderedImage buffers are read-only"));
+        }
+
+        let data = slf.pixmap.data();
+
+        (*view).obj = ffi::_Py_NewRef(slf.as_ptr());
+        (*view).buf = data.as_ptr() as *mut std::ffi::c_void;
+        (*view).len = data.len() as isize;
+        (*view).readonly = 1;
+        (*view).itemsize = 1;
+        (*view).format = if (flags & ffi::PyBUF_FORMAT) == ffi::PyBUF_FORMAT {
+            std::ffi::CStr::from_bytes_with_nul_unchecked(b"B\0").as_ptr() as *mut _
+        } else {
+            std::ptr::null_mut()
+        };
+        (*view).ndim = 3;
+        (*view).shape = if (flags & ffi::PyBUF_ND) == ffi::PyBUF_ND {
+            slf.shape.as_ptr() as *mut isize
+        } else {
+            std::ptr::null_mut()
+        };
+        (*view).strides = if (flags & ffi::PyBUF_STRIDES) == ffi::PyBUF_STRIDES {
+            slf.strides.as_ptr() as *mut isize
+        } else {
+            std::ptr::null_mut()
+        };
+        (*view).suboffsets = std::ptr::null_mut();
+        (*view).internal = std::ptr::null_mut();
+
+        Ok(())
+    }
+
+    unsafe fn __releasebuffer__(&self, _view: *mut ffi::Py_buffer) {}
+}
+
+/// Converts an RGBA buffer in place from premultiplied to straight alpha.
+fn unpremultiply_rgba(data: &mut [u8]) {
+    for pixel in data.chunks_exact_mut(4) {
+        let a = pixel[3];
+        if a == 0 || a == 255 {
+            continue;
+        }
+        for channel in &mut pixel[..3] {
+            *channel = ((*channel as u32 * 255) / a as u32) as u8;
+        }
+    }
+}
+
+/// Returns the tightest `(x, y, width, height)` rectangle containing any
+/// pixel with non-zero alpha, or `None` if `pixmap` is fully transparent.
+fn alpha_bounds(pixmap: &Pixmap) -> Option<(u32, u32, u32, u32)> {
+    let (width, height) = (pixmap.width(), pixmap.height());
+    let pixels = pixmap.pixels();
+
+    let mut min_x = width;
+    let mut min_y = height;
+    let mut max_x = 0u32;
+    let mut max_y = 0u32;
+    let mut found = false;
+
+    for y in 0..height {
+        for x in 0..width {
+            if pixels[(y * width + x) as usize].alpha() != 0 {
+                found = true;
+                min_x = min_x.min(x);
+                min_y = min_y.min(y);
+                max_x = max_x.max(x);
+                max_y = max_y.max(y);
+            }
+        }
+    }
+
+    if !found {
+        return None;
+    }
+
+    Some((min_x, min_y, max_x - min_x + 1, max_y - min_y + 1))
 }
 
 /// Python bindings for resvg.